@@ -0,0 +1,163 @@
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::{self, Debug},
+    hash::{BuildHasherDefault, Hash},
+    marker::PhantomData,
+};
+
+use siphasher::sip128::{Hasher128, SipHasher13};
+use twox_hash::XxHash64;
+
+type Map<K, V> = HashMap<K, V, BuildHasherDefault<XxHash64>>;
+
+/// A `Copy` handle into an [`ArenaTucan`], modeled on rustc's bootstrap
+/// `Interned<T>`: a small integer index into the arena rather than a
+/// ref-counted pointer.
+///
+/// Equality and ordering are plain integer comparisons on the slot index,
+/// which makes `Handle<T>` a cheap, cache-friendly key for other maps.
+///
+/// A `Handle<T>` is only meaningful against the [`ArenaTucan`] that produced
+/// it: indices are not namespaced per arena, so resolving a handle from one
+/// arena against a different same-`T` arena will silently return whatever
+/// value happens to occupy that slot there, or panic if the other arena is
+/// shorter. Don't mix handles from different `ArenaTucan` instances.
+pub struct Handle<T>(u32, PhantomData<T>);
+
+impl<T> Handle<T> {
+    /// Returns the raw arena slot backing this handle.
+    #[inline]
+    #[must_use]
+    pub fn index(self) -> u32 {
+        self.0
+    }
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Handle<T> {}
+
+impl<T> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for Handle<T> {}
+
+impl<T> Ord for Handle<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T> PartialOrd for Handle<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Hash for Handle<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<T> Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Handle").field(&self.0).finish()
+    }
+}
+
+/// An append-only arena interner that hands out `Copy` integer [`Handle`]s
+/// instead of ref-counted pointers.
+///
+/// Unlike the `Rc`/`Arc`-backed interners in this crate, values are never
+/// freed: the arena only ever grows. This trades away `gc()` in exchange for
+/// handles that are stable, compact, and trivially copyable for the lifetime
+/// of the arena.
+pub struct ArenaTucan<T: Hash + Eq> {
+    arena: RefCell<Vec<Box<T>>>,
+    index: RefCell<Map<u128, Vec<u32>>>,
+}
+
+impl<T: Hash + Eq> Default for ArenaTucan<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq> ArenaTucan<T> {
+    /// Creates a new, empty arena.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            arena: RefCell::new(Vec::new()),
+            index: RefCell::new(Map::default()),
+        }
+    }
+
+    /// Returns the number of values interned.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.arena.borrow().len()
+    }
+
+    /// Returns `true` if the arena is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Interns a value, deduplicating by content hash (verified against the
+    /// stored value, since two distinct values can share a hash), and
+    /// returns a stable handle to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena already holds more than `u32::MAX` values.
+    pub fn intern(&self, value: T) -> Handle<T> {
+        let hash = hash128(&value);
+
+        let mut index = self.index.borrow_mut();
+        let chain = index.entry(hash).or_default();
+
+        let arena = self.arena.borrow();
+        if let Some(&slot) = chain.iter().find(|&&slot| *arena[slot as usize] == value) {
+            return Handle(slot, PhantomData);
+        }
+        drop(arena);
+
+        let mut arena = self.arena.borrow_mut();
+        let slot = u32::try_from(arena.len()).expect("arena holds more than u32::MAX values");
+        arena.push(Box::new(value));
+        chain.push(slot);
+        Handle(slot, PhantomData)
+    }
+
+    /// Resolves a handle back to the interned value.
+    ///
+    /// The handle must have come from this same `ArenaTucan`; resolving a
+    /// handle produced by a different instance is not checked and will
+    /// return the wrong value (or panic on an out-of-range index).
+    #[must_use]
+    pub fn resolve(&self, handle: Handle<T>) -> &T {
+        let arena = self.arena.borrow();
+        // SAFETY: the arena is append-only and each value is heap-allocated
+        // individually, so growing the outer `Vec` never moves or frees a
+        // previously interned value, and a slot is only ever written once.
+        unsafe { &*std::ptr::from_ref(arena[handle.0 as usize].as_ref()) }
+    }
+}
+
+fn hash128<T: Hash>(value: &T) -> u128 {
+    let mut hasher = SipHasher13::new();
+    value.hash(&mut hasher);
+    hasher.finish128().as_u128()
+}
@@ -1,21 +1,25 @@
 use std::{
+    any::{Any, TypeId},
     fmt::Debug,
     hash::{BuildHasherDefault, Hash},
+    marker::PhantomData,
     ops::Deref,
     ptr::addr_of,
-    sync::Arc,
+    sync::{Arc, Weak},
 };
 
 use dashmap::DashMap;
 use siphasher::sip128::{Hasher128, SipHasher13};
 use twox_hash::XxHash64;
 
+use crate::{Intern, Interned};
+
 type Map<K, V> = DashMap<K, V, BuildHasherDefault<XxHash64>>;
 
 /// A unique ID for a value within the interner.
 pub struct AInterned<T: Hash + Send + Sync + ?Sized>(Arc<T>);
 
-pub trait ConcurrentIntern<T: Hash + Send + Sync + ?Sized = Self>: Hash + Send + Sync {
+pub trait ConcurrentIntern<T: Hash + Eq + Send + Sync + ?Sized = Self>: Hash + Eq + Send + Sync {
     fn intern(self, interner: &Tucan<T>) -> AInterned<T>;
 }
 
@@ -27,7 +31,7 @@ impl ConcurrentIntern<str> for &str {
 
 default impl<T> ConcurrentIntern for T
 where
-    T: Hash + Send + Sync + Sized,
+    T: Hash + Eq + Send + Sync + Sized,
 {
     fn intern(self, interner: &Tucan<Self>) -> AInterned<Self> {
         interner.intern(self)
@@ -144,24 +148,30 @@ where
     }
 }
 
-pub struct Tucan<T: Hash + Send + Sync + ?Sized>(Map<u128, Arc<T>>);
+/// The concurrent, `DashMap`-backed counterpart of [`crate::AnyTucan`]: a
+/// per-`T` interner with the same weak-slot collision-chain storage (see
+/// [`crate::AnyTucan`] for the full rationale), usable from multiple threads.
+pub struct Tucan<T: Hash + Eq + Send + Sync + ?Sized>(Map<u128, Vec<Weak<T>>>);
 
-impl<T: Hash + Send + Sync + ?Sized> Default for Tucan<T> {
+impl<T: Hash + Eq + Send + Sync + ?Sized> Default for Tucan<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T: Hash + Send + Sync + ?Sized> Tucan<T> {
+impl<T: Hash + Eq + Send + Sync + ?Sized> Tucan<T> {
     /// Creates a new interner.
     #[must_use]
     pub fn new() -> Self {
         Self(Map::default())
     }
 
-    /// Cleans up the values that are interned but no longer referenced.
+    /// Removes slots whose value has already been freed.
     pub fn gc(&self) {
-        self.0.retain(|_, item| Arc::strong_count(item) > 1);
+        self.0.retain(|_, chain| {
+            chain.retain(|weak| weak.strong_count() > 0);
+            !chain.is_empty()
+        });
     }
 
     /// Clears the interner but does not free the memory.
@@ -169,16 +179,25 @@ impl<T: Hash + Send + Sync + ?Sized> Tucan<T> {
         self.0.clear();
     }
 
-    /// Returns the number of values interned.
+    /// Returns the number of values currently interned.
     #[must_use]
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.0
+            .iter()
+            .map(|entry| {
+                entry
+                    .value()
+                    .iter()
+                    .filter(|weak| weak.strong_count() > 0)
+                    .count()
+            })
+            .sum()
     }
 
     /// Returns `true` if the interner is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.len() == 0
     }
 
     /// Interns a value.
@@ -188,13 +207,26 @@ impl<T: Hash + Send + Sync + ?Sized> Tucan<T> {
     {
         let hash = hash128(&value);
 
-        if let Some(item) = self.0.get(&hash) {
-            AInterned(Arc::clone(item.value()))
-        } else {
-            let ptr: Arc<T> = Arc::new(value);
-            self.0.insert(hash, Arc::clone(&ptr));
-            AInterned(ptr)
+        let mut chain = self.0.entry(hash).or_default();
+
+        let mut dead_slot = None;
+        for (index, weak) in chain.iter().enumerate() {
+            match weak.upgrade() {
+                Some(item) if *item == value => return AInterned(item),
+                Some(_) => {}
+                None => {
+                    dead_slot.get_or_insert(index);
+                }
+            }
+        }
+
+        let ptr: Arc<T> = Arc::new(value);
+        let weak = Arc::downgrade(&ptr);
+        match dead_slot {
+            Some(index) => chain[index] = weak,
+            None => chain.push(weak),
         }
+        AInterned(ptr)
     }
 }
 
@@ -204,28 +236,143 @@ impl Tucan<str> {
     pub fn intern_str(&self, value: &str) -> AInterned<str> {
         let hash = hash128(&value);
 
-        if let Some(item) = self.0.get(&hash) {
-            AInterned(Arc::clone(item.value()))
-        } else {
-            let ptr: Arc<str> = Arc::from(value);
-            self.0.insert(hash, Arc::clone(&ptr));
-            AInterned(ptr)
+        let mut chain = self.0.entry(hash).or_default();
+
+        let mut dead_slot = None;
+        for (index, weak) in chain.iter().enumerate() {
+            match weak.upgrade() {
+                Some(item) if item.as_ref() == value => return AInterned(item),
+                Some(_) => {}
+                None => {
+                    dead_slot.get_or_insert(index);
+                }
+            }
         }
+
+        let ptr: Arc<str> = Arc::from(value);
+        let weak = Arc::downgrade(&ptr);
+        match dead_slot {
+            Some(index) => chain[index] = weak,
+            None => chain.push(weak),
+        }
+        AInterned(ptr)
     }
 }
 
-impl<T: Sized + Hash + Clone + Send + Sync> Tucan<[T]> {
+impl<T: Sized + Hash + Eq + Clone + Send + Sync> Tucan<[T]> {
     /// Interns a slice.
     pub fn intern_slice(&self, value: &[T]) -> AInterned<[T]> {
         let hash = hash128(&value);
 
-        if let Some(item) = self.0.get(&hash) {
-            AInterned(Arc::clone(item.value()))
-        } else {
-            let ptr: Arc<[T]> = Arc::<[T]>::from(value);
-            self.0.insert(hash, Arc::clone(&ptr));
-            AInterned(ptr)
+        let mut chain = self.0.entry(hash).or_default();
+
+        let mut dead_slot = None;
+        for (index, weak) in chain.iter().enumerate() {
+            match weak.upgrade() {
+                Some(item) if item.as_ref() == value => return AInterned(item),
+                Some(_) => {}
+                None => {
+                    dead_slot.get_or_insert(index);
+                }
+            }
+        }
+
+        let ptr: Arc<[T]> = Arc::<[T]>::from(value);
+        let weak = Arc::downgrade(&ptr);
+        match dead_slot {
+            Some(index) => chain[index] = weak,
+            None => chain.push(weak),
+        }
+        AInterned(ptr)
+    }
+}
+
+/// A heterogeneous, concurrent interner keyed by `(TypeId, content hash)`,
+/// scoped to a single owned, droppable instance.
+///
+/// This is the concurrent counterpart of [`crate::AnyTucan`]: a single
+/// `ConcurrentAnyTucan` can intern strings, slices, and arbitrary
+/// `Hash + Eq + Send + Sync` types side by side, all behind the same
+/// [`Interned`] handle, and can be shared across threads like [`Tucan`].
+pub struct ConcurrentAnyTucan(Map<(TypeId, u128), Vec<Weak<dyn Any + Send + Sync>>>);
+
+impl Default for ConcurrentAnyTucan {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConcurrentAnyTucan {
+    /// Creates a new, empty interner.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Map::default())
+    }
+
+    /// Removes slots whose value has already been freed.
+    pub fn gc(&self) {
+        self.0.retain(|_, chain| {
+            chain.retain(|weak| weak.strong_count() > 0);
+            !chain.is_empty()
+        });
+    }
+
+    /// Clears the interner but does not free the memory.
+    pub fn clear(&self) {
+        self.0.clear();
+    }
+
+    /// Returns the number of values currently interned.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0
+            .iter()
+            .map(|entry| {
+                entry
+                    .value()
+                    .iter()
+                    .filter(|weak| weak.strong_count() > 0)
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Returns `true` if the interner is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Interns a value.
+    pub fn intern<T>(&self, value: T) -> Interned<T>
+    where
+        T: Intern,
+    {
+        let type_id = TypeId::of::<T>();
+        let hash = hash128(&value);
+
+        let mut chain = self.0.entry((type_id, hash)).or_default();
+
+        let mut dead_slot = None;
+        for (index, weak) in chain.iter().enumerate() {
+            match weak.upgrade() {
+                Some(item) if item.downcast_ref::<T>() == Some(&value) => {
+                    return Interned(item, PhantomData::<T>);
+                }
+                Some(_) => {}
+                None => {
+                    dead_slot.get_or_insert(index);
+                }
+            };
+        }
+
+        let ptr: Arc<dyn Any + Send + Sync> = Arc::new(value);
+        let weak = Arc::downgrade(&ptr);
+        match dead_slot {
+            Some(index) => chain[index] = weak,
+            None => chain.push(weak),
         }
+        Interned(ptr, PhantomData)
     }
 }
 
@@ -1,3 +1,6 @@
+// `concurrent` and `singlethread` each specialize their blanket
+// `ConcurrentIntern`/`LocalIntern` impl for `&str`, mirroring rustc's own use of specialization for this pattern.
+#![feature(specialization)]
 #![deny(
     absolute_paths_not_starting_with_crate,
     keyword_idents,
@@ -23,7 +26,7 @@ use std::{
     marker::PhantomData,
     ops::Deref,
     ptr::addr_of,
-    sync::Arc,
+    sync::{Arc, Weak},
 };
 
 use once_cell::sync::Lazy;
@@ -31,21 +34,33 @@ use parking_lot::RwLock;
 use siphasher::sip128::{Hasher128, SipHasher13};
 use twox_hash::XxHash64;
 
+mod arena;
+
+#[cfg(feature = "concurrent")]
+mod concurrent;
+
+mod singlethread;
+
+pub use arena::{ArenaTucan, Handle};
+#[cfg(feature = "concurrent")]
+pub use concurrent::{AInterned, ConcurrentAnyTucan, ConcurrentIntern, Tucan};
+pub use singlethread::{LocalIntern, LocalTucan, RInterned};
+
 type Map<K, V> = HashMap<K, V, BuildHasherDefault<XxHash64>>;
 
-static TUCAN: Lazy<Tucan> = Lazy::new(Tucan::new);
+static TUCAN: Lazy<AnyTucan> = Lazy::new(AnyTucan::new);
 
 /// A unique ID for a value within the interner.
 #[derive(Clone)]
 pub struct Interned<T: Intern>(Arc<dyn Any + Send + Sync>, PhantomData<T>);
 
-pub trait Intern: Any + Hash + Send + Sync + Sized {
+pub trait Intern: Any + Hash + Eq + Send + Sync + Sized {
     fn intern(self) -> Interned<Self>;
 }
 
 impl<T> Intern for T
 where
-    T: Any + Hash + Send + Sync + Sized,
+    T: Any + Hash + Eq + Send + Sync + Sized,
 {
     fn intern(self) -> Interned<Self> {
         intern(self)
@@ -146,22 +161,105 @@ where
     }
 }
 
-struct Tucan(RwLock<Map<(TypeId, u128), Arc<dyn Any + Send + Sync>>>);
+impl<T> Interned<T>
+where
+    T: Intern,
+{
+    /// Returns this value's stable content fingerprint.
+    ///
+    /// The fingerprint is derived purely from the value's `Hash` content, so
+    /// it is stable across clones of this handle and, for a given hasher,
+    /// across program runs: it can be persisted and later resolved back to
+    /// an `Interned<T>` via [`AnyTucan::get_by_fingerprint`] without needing
+    /// the original value.
+    #[must_use]
+    pub fn fingerprint(&self) -> Fingerprint {
+        Fingerprint(hash128(self.as_ref()))
+    }
+}
+
+/// A stable, content-derived identifier for an interned value, akin to
+/// rustc's `Fingerprint` for incremental caching.
+///
+/// Two values that are `Hash`- and `Eq`-equal always produce the same
+/// fingerprint within a run, so it is a safe, compact stand-in for the value
+/// itself when persisting to disk or sending over the wire.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Fingerprint(u128);
+
+impl Fingerprint {
+    /// Returns the raw 128-bit hash backing this fingerprint.
+    #[must_use]
+    pub fn as_u128(self) -> u128 {
+        self.0
+    }
+}
+
+/// A heterogeneous interner keyed by `(TypeId, content hash)`, scoped to a
+/// single owned, droppable instance rather than the process-wide global
+/// interner backing the free [`intern`] function.
+///
+/// Each bucket is a collision chain of *weak* slots rather than a single
+/// strong value: two distinct values can share a 128-bit hash, so a hit is
+/// only reused once it is verified against the stored value with `Eq`, and a
+/// genuine collision grows the chain instead of silently unifying the two
+/// values. Because the map only ever holds a `Weak`, a value is freed the
+/// moment its last [`Interned`] handle drops; [`AnyTucan::gc`] is then just a
+/// sweep that drops the slots whose strong count has reached zero, with no
+/// reference held by the map itself to account for.
+///
+/// A single `AnyTucan` can intern strings, slices, and arbitrary `Hash + Eq`
+/// types side by side, all behind the same [`Interned`] handle — mirroring
+/// how rustc's `TyCtxt` funnels many interned kinds through one context
+/// instead of many per-type globals.
+pub struct AnyTucan(RwLock<Map<(TypeId, u128), Vec<Weak<dyn Any + Send + Sync>>>>);
 
-impl Tucan {
-    /// Creates a new interner.
-    fn new() -> Self {
+impl Default for AnyTucan {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnyTucan {
+    /// Creates a new, empty interner.
+    #[must_use]
+    pub fn new() -> Self {
         Self(RwLock::new(HashMap::default()))
     }
 
-    /// Cleans up the values that are interned but no longer referenced.
-    fn gc(&self) {
+    /// Removes slots whose value has already been freed.
+    pub fn gc(&self) {
         let mut map = self.0.write();
-        map.retain(|_, item| Arc::strong_count(item) > 1);
+        map.retain(|_, chain| {
+            chain.retain(|weak| weak.strong_count() > 0);
+            !chain.is_empty()
+        });
+    }
+
+    /// Clears the interner but does not free the memory.
+    pub fn clear(&self) {
+        self.0.write().clear();
+    }
+
+    /// Returns the number of values currently interned.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0
+            .read()
+            .values()
+            .flatten()
+            .filter(|weak| weak.strong_count() > 0)
+            .count()
+    }
+
+    /// Returns `true` if the interner is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     /// Interns a value.
-    fn intern<T>(&self, value: T) -> Interned<T>
+    pub fn intern<T>(&self, value: T) -> Interned<T>
     where
         T: Intern,
     {
@@ -169,13 +267,57 @@ impl Tucan {
         let hash = hash128(&value);
 
         let mut map = self.0.write();
-        if let Some(item) = map.get(&(type_id, hash)) {
-            Interned(Arc::clone(item), PhantomData::<T>)
-        } else {
-            let ptr: Arc<dyn Any + Send + Sync> = Arc::new(value);
-            map.insert((type_id, hash), Arc::clone(&ptr));
-            Interned(ptr, PhantomData)
+        let chain = map.entry((type_id, hash)).or_default();
+
+        let mut dead_slot = None;
+        for (index, weak) in chain.iter().enumerate() {
+            match weak.upgrade() {
+                Some(item) if item.downcast_ref::<T>() == Some(&value) => {
+                    return Interned(item, PhantomData::<T>);
+                }
+                Some(_) => {}
+                None => {
+                    dead_slot.get_or_insert(index);
+                }
+            };
         }
+
+        let ptr: Arc<dyn Any + Send + Sync> = Arc::new(value);
+        let weak = Arc::downgrade(&ptr);
+        match dead_slot {
+            Some(index) => chain[index] = weak,
+            None => chain.push(weak),
+        }
+        Interned(ptr, PhantomData)
+    }
+
+    /// Interns a value and also returns its stable fingerprint.
+    pub fn intern_with_fingerprint<T>(&self, value: T) -> (Interned<T>, Fingerprint)
+    where
+        T: Intern,
+    {
+        let fingerprint = Fingerprint(hash128(&value));
+        (self.intern(value), fingerprint)
+    }
+
+    /// Looks up a previously interned value by its fingerprint, without
+    /// needing the original value.
+    ///
+    /// Returns `None` if no live value with that fingerprint and type is
+    /// currently interned.
+    #[must_use]
+    pub fn get_by_fingerprint<T>(&self, fingerprint: Fingerprint) -> Option<Interned<T>>
+    where
+        T: Intern,
+    {
+        let type_id = TypeId::of::<T>();
+        let map = self.0.read();
+        let chain = map.get(&(type_id, fingerprint.0))?;
+        chain.iter().find_map(|weak| {
+            let item = weak.upgrade()?;
+            item.downcast_ref::<T>()?;
+            Some(Interned(item, PhantomData))
+        })
     }
 }
 
@@ -186,14 +328,12 @@ pub fn gc() {
 
 /// Clears the interner but does not free the memory.
 pub fn clear() {
-    let mut map = TUCAN.0.write();
-    map.clear();
+    TUCAN.clear();
 }
 
 /// Returns the number of values interned.
 pub fn len() -> usize {
-    let map = TUCAN.0.read();
-    map.len()
+    TUCAN.len()
 }
 
 /// Interns a value.
@@ -204,6 +344,24 @@ where
     TUCAN.intern(value)
 }
 
+/// Interns a value and also returns its stable fingerprint.
+pub fn intern_with_fingerprint<T>(value: T) -> (Interned<T>, Fingerprint)
+where
+    T: Intern,
+{
+    TUCAN.intern_with_fingerprint(value)
+}
+
+/// Looks up a previously interned value by its fingerprint, without needing
+/// the original value.
+#[must_use]
+pub fn get_by_fingerprint<T>(fingerprint: Fingerprint) -> Option<Interned<T>>
+where
+    T: Intern,
+{
+    TUCAN.get_by_fingerprint(fingerprint)
+}
+
 fn hash128<T: Hash>(value: &T) -> u128 {
     let mut hasher = SipHasher13::new();
     value.hash(&mut hasher);
@@ -1,9 +1,11 @@
 use std::{
+    cell::RefCell,
+    collections::HashMap,
     fmt::Debug,
     hash::{BuildHasherDefault, Hash},
     ops::Deref,
     ptr::addr_of,
-    rc::Rc, collections::HashMap, cell::RefCell,
+    rc::{Rc, Weak},
 };
 
 use siphasher::sip128::{Hasher128, SipHasher13};
@@ -12,28 +14,28 @@ use twox_hash::XxHash64;
 type Map<K, V> = HashMap<K, V, BuildHasherDefault<XxHash64>>;
 
 /// A unique ID for a value within the interner.
-pub struct Interned<T: Hash + ?Sized>(Rc<T>);
+pub struct RInterned<T: Hash + ?Sized>(Rc<T>);
 
-pub trait Intern<T: Hash + ?Sized = Self>: Hash {
-    fn intern(self, interner: &Tucan<T>) -> Interned<T>;
+pub trait LocalIntern<T: Hash + Eq + ?Sized = Self>: Hash + Eq {
+    fn intern(self, interner: &LocalTucan<T>) -> RInterned<T>;
 }
 
-impl Intern<str> for &str {
-    fn intern(self, interner: &Tucan<str>) -> Interned<str> {
+impl LocalIntern<str> for &str {
+    fn intern(self, interner: &LocalTucan<str>) -> RInterned<str> {
         interner.intern_str(self)
     }
 }
 
-default impl<T> Intern for T
+default impl<T> LocalIntern for T
 where
-    T: Hash + Sized,
+    T: Hash + Eq + Sized,
 {
-    fn intern(self, interner: &Tucan<Self>) -> Interned<Self> {
+    fn intern(self, interner: &LocalTucan<Self>) -> RInterned<Self> {
         interner.intern(self)
     }
 }
 
-impl<T> Clone for Interned<T>
+impl<T> Clone for RInterned<T>
 where
     T: Hash + ?Sized,
 {
@@ -42,7 +44,7 @@ where
     }
 }
 
-impl<T> Hash for Interned<T>
+impl<T> Hash for RInterned<T>
 where
     T: Hash + ?Sized,
 {
@@ -51,7 +53,7 @@ where
     }
 }
 
-impl<T> Interned<T>
+impl<T> RInterned<T>
 where
     T: Hash + ?Sized,
 {
@@ -63,16 +65,16 @@ where
     }
 }
 
-impl<T> Debug for Interned<T>
+impl<T> Debug for RInterned<T>
 where
     T: Hash + ?Sized + Debug,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("Interned").field(&self.as_ref()).finish()
+        f.debug_tuple("RInterned").field(&self.as_ref()).finish()
     }
 }
 
-impl<T> AsRef<T> for Interned<T>
+impl<T> AsRef<T> for RInterned<T>
 where
     T: Hash + ?Sized,
 {
@@ -81,7 +83,7 @@ where
     }
 }
 
-impl<T> Deref for Interned<T>
+impl<T> Deref for RInterned<T>
 where
     T: Hash + ?Sized,
 {
@@ -92,7 +94,7 @@ where
     }
 }
 
-impl<T> PartialEq for Interned<T>
+impl<T> PartialEq for RInterned<T>
 where
     T: Hash + ?Sized,
 {
@@ -102,7 +104,7 @@ where
     }
 }
 
-impl<T> PartialEq<T> for Interned<T>
+impl<T> PartialEq<T> for RInterned<T>
 where
     T: Hash + ?Sized + PartialEq,
 {
@@ -111,21 +113,21 @@ where
     }
 }
 
-impl PartialEq<&str> for Interned<str>
+impl PartialEq<&str> for RInterned<str>
 {
     fn eq(&self, other: &&str) -> bool {
         self.as_ref() == *other
     }
 }
 
-impl<T: Hash + Sized + PartialEq> PartialEq<&[T]> for Interned<[T]>
+impl<T: Hash + Sized + PartialEq> PartialEq<&[T]> for RInterned<[T]>
 {
     fn eq(&self, other: &&[T]) -> bool {
         self.as_ref() == *other
     }
 }
 
-impl<T> PartialOrd for Interned<T>
+impl<T> PartialOrd for RInterned<T>
 where
     T: Hash + ?Sized + PartialOrd,
 {
@@ -134,7 +136,7 @@ where
     }
 }
 
-impl<T> PartialOrd<T> for Interned<T>
+impl<T> PartialOrd<T> for RInterned<T>
 where
     T: Hash + ?Sized + PartialOrd,
 {
@@ -143,24 +145,32 @@ where
     }
 }
 
-pub struct Tucan<T: Hash + ?Sized>(RefCell<Map<u128, Rc<T>>>);
+/// The single-threaded counterpart of [`crate::AnyTucan`] and the
+/// `concurrent` module's `Tucan`: an `Rc`-backed, per-`T` interner with the
+/// same weak-slot collision-chain storage (see [`crate::AnyTucan`] for the
+/// full rationale), but built on `RefCell` instead of `RwLock`/`DashMap` for
+/// single-threaded use.
+pub struct LocalTucan<T: Hash + Eq + ?Sized>(RefCell<Map<u128, Vec<Weak<T>>>>);
 
-impl<T: Hash + ?Sized> Default for Tucan<T> {
+impl<T: Hash + Eq + ?Sized> Default for LocalTucan<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T: Hash + ?Sized> Tucan<T> {
+impl<T: Hash + Eq + ?Sized> LocalTucan<T> {
     /// Creates a new interner.
     #[must_use]
     pub fn new() -> Self {
         Self(RefCell::new(Map::default()))
     }
 
-    /// Cleans up the values that are interned but no longer referenced.
+    /// Removes slots whose value has already been freed.
     pub fn gc(&self) {
-        self.0.borrow_mut().retain(|_, item| Rc::strong_count(item) > 1);
+        self.0.borrow_mut().retain(|_, chain| {
+            chain.retain(|weak| weak.strong_count() > 0);
+            !chain.is_empty()
+        });
     }
 
     /// Clears the interner but does not free the memory.
@@ -168,72 +178,110 @@ impl<T: Hash + ?Sized> Tucan<T> {
         self.0.borrow_mut().clear();
     }
 
-    /// Returns the number of values interned.
+    /// Returns the number of values currently interned.
     #[must_use]
     pub fn len(&self) -> usize {
-        self.0.borrow().len()
+        self.0
+            .borrow()
+            .values()
+            .flatten()
+            .filter(|weak| weak.strong_count() > 0)
+            .count()
     }
 
     /// Returns `true` if the interner is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.0.borrow().is_empty()
+        self.len() == 0
     }
 
     /// Interns a value.
-    pub fn intern(&self, value: T) -> Interned<T>
+    pub fn intern(&self, value: T) -> RInterned<T>
     where
         T: Sized,
     {
         let hash = hash128(&value);
 
-        let borrow = self.0.borrow();
-        if let Some(item) = borrow.get(&hash) {
-            Interned(Rc::clone(item))
-        } else {
-            drop(borrow);
+        let mut map = self.0.borrow_mut();
+        let chain = map.entry(hash).or_default();
+
+        let mut dead_slot = None;
+        for (index, weak) in chain.iter().enumerate() {
+            match weak.upgrade() {
+                Some(item) if *item == value => return RInterned(item),
+                Some(_) => {}
+                None => {
+                    dead_slot.get_or_insert(index);
+                }
+            }
+        }
 
-            let ptr: Rc<T> = Rc::new(value);
-            self.0.borrow_mut().insert(hash, Rc::clone(&ptr));
-            Interned(ptr)
+        let ptr: Rc<T> = Rc::new(value);
+        let weak = Rc::downgrade(&ptr);
+        match dead_slot {
+            Some(index) => chain[index] = weak,
+            None => chain.push(weak),
         }
+        RInterned(ptr)
     }
 }
 
-impl Tucan<str> {
+impl LocalTucan<str> {
     /// Interns a string.
     #[must_use]
-    pub fn intern_str(&self, value: &str) -> Interned<str> {
+    pub fn intern_str(&self, value: &str) -> RInterned<str> {
         let hash = hash128(&value);
 
-        let borrow = self.0.borrow();
-        if let Some(item) = borrow.get(&hash) {
-            Interned(Rc::clone(item))
-        } else {
-            drop(borrow);
+        let mut map = self.0.borrow_mut();
+        let chain = map.entry(hash).or_default();
+
+        let mut dead_slot = None;
+        for (index, weak) in chain.iter().enumerate() {
+            match weak.upgrade() {
+                Some(item) if item.as_ref() == value => return RInterned(item),
+                Some(_) => {}
+                None => {
+                    dead_slot.get_or_insert(index);
+                }
+            }
+        }
 
-            let ptr: Rc<str> = Rc::from(value);
-            self.0.borrow_mut().insert(hash, Rc::clone(&ptr));
-            Interned(ptr)
+        let ptr: Rc<str> = Rc::from(value);
+        let weak = Rc::downgrade(&ptr);
+        match dead_slot {
+            Some(index) => chain[index] = weak,
+            None => chain.push(weak),
         }
+        RInterned(ptr)
     }
 }
 
-impl<T: Sized + Hash + Clone> Tucan<[T]> {
+impl<T: Sized + Hash + Eq + Clone> LocalTucan<[T]> {
     /// Interns a slice.
-    pub fn intern_slice(&self, value: &[T]) -> Interned<[T]> {
+    pub fn intern_slice(&self, value: &[T]) -> RInterned<[T]> {
         let hash = hash128(&value);
 
-        let borrow = self.0.borrow();
-        if let Some(item) = borrow.get(&hash) {
-            Interned(Rc::clone(item))
-        } else {
-            drop(borrow);
+        let mut map = self.0.borrow_mut();
+        let chain = map.entry(hash).or_default();
+
+        let mut dead_slot = None;
+        for (index, weak) in chain.iter().enumerate() {
+            match weak.upgrade() {
+                Some(item) if item.as_ref() == value => return RInterned(item),
+                Some(_) => {}
+                None => {
+                    dead_slot.get_or_insert(index);
+                }
+            }
+        }
 
-            let ptr: Rc<[T]> = Rc::<[T]>::from(value);
-            self.0.borrow_mut().insert(hash, Rc::clone(&ptr));
-            Interned(ptr)
+        let ptr: Rc<[T]> = Rc::<[T]>::from(value);
+        let weak = Rc::downgrade(&ptr);
+        match dead_slot {
+            Some(index) => chain[index] = weak,
+            None => chain.push(weak),
         }
+        RInterned(ptr)
     }
 }
 
@@ -0,0 +1,21 @@
+use tucan::ArenaTucan;
+
+#[test]
+pub fn test_interner() {
+    let arena = ArenaTucan::<String>::new();
+
+    let a = arena.intern("hello".to_string());
+    let b = arena.intern("hello".to_string());
+    let c = arena.intern("world".to_string());
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_ne!(b, c);
+
+    assert_eq!(arena.resolve(a), "hello");
+    assert_eq!(arena.resolve(b), "hello");
+    assert_eq!(arena.resolve(c), "world");
+
+    assert_eq!(arena.len(), 2);
+    assert!(!arena.is_empty());
+}
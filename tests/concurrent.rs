@@ -1,12 +1,14 @@
 #[cfg(feature = "concurrent")]
-use tucan::{concurrent_gc, AInterned, ConcurrentIntern};
+use tucan::{AInterned, ConcurrentIntern, Tucan};
 
 #[cfg(feature = "concurrent")]
 #[test]
 pub fn test_interner() {
-    let a = "hello".intern();
-    let b = "hello".intern();
-    let c = "world".intern();
+    let interner = Tucan::<str>::new();
+
+    let a = "hello".intern(&interner);
+    let b = "hello".intern(&interner);
+    let c = "world".intern(&interner);
 
     assert_eq!(a, b);
     assert_ne!(a, c);
@@ -16,41 +18,43 @@ pub fn test_interner() {
     assert_eq!(b, "hello");
     assert_eq!(c, "world");
 
-    assert_eq!(a, "hello".intern());
-    assert_eq!(b, "hello".intern());
-    assert_eq!(c, "world".intern());
+    assert_eq!(a, "hello".intern(&interner));
+    assert_eq!(b, "hello".intern(&interner));
+    assert_eq!(c, "world".intern(&interner));
 
-    assert_eq!(AInterned::strong_count(&a), 3);
-    assert_eq!(AInterned::strong_count(&b), 3);
-    assert_eq!(AInterned::strong_count(&c), 2);
+    assert_eq!(AInterned::strong_count(&a), 2);
+    assert_eq!(AInterned::strong_count(&b), 2);
+    assert_eq!(AInterned::strong_count(&c), 1);
 
     let aa = a.clone();
     let bb = b.clone();
     let cb = c.clone();
 
-    assert_eq!(AInterned::strong_count(&a), 5);
-    assert_eq!(AInterned::strong_count(&b), 5);
-    assert_eq!(AInterned::strong_count(&c), 3);
+    assert_eq!(AInterned::strong_count(&a), 4);
+    assert_eq!(AInterned::strong_count(&b), 4);
+    assert_eq!(AInterned::strong_count(&c), 2);
 
     drop(aa);
     drop(bb);
     drop(cb);
 
-    assert_eq!(AInterned::strong_count(&a), 3);
-    assert_eq!(AInterned::strong_count(&b), 3);
-    assert_eq!(AInterned::strong_count(&c), 2);
+    assert_eq!(AInterned::strong_count(&a), 2);
+    assert_eq!(AInterned::strong_count(&b), 2);
+    assert_eq!(AInterned::strong_count(&c), 1);
 
     drop(a);
 
-    assert_eq!(AInterned::strong_count(&b), 2);
-    assert_eq!(AInterned::strong_count(&c), 2);
+    assert_eq!(AInterned::strong_count(&b), 1);
+    assert_eq!(AInterned::strong_count(&c), 1);
 
     drop(b);
     drop(c);
 
-    assert_eq!(tucan::concurrent_len(), 2);
+    // The interner never held a strong reference, so both values are
+    // reclaimed the moment their last handle drops, with no `gc()` needed.
+    assert_eq!(interner.len(), 0);
 
-    concurrent_gc();
+    interner.gc();
 
-    assert_eq!(tucan::concurrent_len(), 0);
+    assert_eq!(interner.len(), 0);
 }
@@ -0,0 +1,31 @@
+#[cfg(feature = "concurrent")]
+use tucan::{ConcurrentAnyTucan, Interned};
+
+#[cfg(feature = "concurrent")]
+#[test]
+pub fn test_interner() {
+    let interner = ConcurrentAnyTucan::new();
+
+    let a: Interned<&str> = interner.intern("hello");
+    let b: Interned<&str> = interner.intern("hello");
+    let c: Interned<&str> = interner.intern("world");
+    let n: Interned<u32> = interner.intern(42);
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+
+    assert_eq!(a, "hello");
+    assert_eq!(n, 42);
+
+    assert_eq!(interner.len(), 3);
+
+    drop(a);
+    drop(b);
+    drop(c);
+    drop(n);
+
+    interner.gc();
+
+    assert_eq!(interner.len(), 0);
+    assert!(interner.is_empty());
+}
@@ -0,0 +1,30 @@
+use tucan::AnyTucan;
+
+#[test]
+pub fn test_fingerprint_round_trip() {
+    let interner = AnyTucan::new();
+
+    let a = interner.intern("hello");
+    let b = interner.intern("hello");
+    let c = interner.intern("world");
+
+    assert_eq!(a.fingerprint(), b.fingerprint());
+    assert_ne!(a.fingerprint(), c.fingerprint());
+
+    let (d, fingerprint) = interner.intern_with_fingerprint("hello");
+    assert_eq!(d, a);
+    assert_eq!(fingerprint, a.fingerprint());
+
+    let resolved: Option<tucan::Interned<&str>> = interner.get_by_fingerprint(fingerprint);
+    assert_eq!(resolved, Some(a.clone()));
+    drop(resolved);
+
+    drop(a);
+    drop(b);
+    drop(c);
+    drop(d);
+
+    // Once every handle has dropped, the fingerprint no longer resolves.
+    let resolved: Option<tucan::Interned<&str>> = interner.get_by_fingerprint(fingerprint);
+    assert_eq!(resolved, None);
+}
@@ -1,9 +1,9 @@
-use tucan::{Intern, Interned, Tucan};
+use tucan::{LocalIntern, LocalTucan, RInterned};
 
 #[test]
 pub fn test_interner() {
-    let interner = Tucan::<str>::new();
-    
+    let interner = LocalTucan::<str>::new();
+
     let a = "hello".intern(&interner);
     let b = "hello".intern(&interner);
     let c = "world".intern(&interner);
@@ -20,35 +20,37 @@ pub fn test_interner() {
     assert_eq!(b, "hello".intern(&interner));
     assert_eq!(c, "world".intern(&interner));
 
-    assert_eq!(Interned::strong_count(&a), 3);
-    assert_eq!(Interned::strong_count(&b), 3);
-    assert_eq!(Interned::strong_count(&c), 2);
+    assert_eq!(RInterned::strong_count(&a), 2);
+    assert_eq!(RInterned::strong_count(&b), 2);
+    assert_eq!(RInterned::strong_count(&c), 1);
 
     let aa = a.clone();
     let bb = b.clone();
     let cb = c.clone();
 
-    assert_eq!(Interned::strong_count(&a), 5);
-    assert_eq!(Interned::strong_count(&b), 5);
-    assert_eq!(Interned::strong_count(&c), 3);
+    assert_eq!(RInterned::strong_count(&a), 4);
+    assert_eq!(RInterned::strong_count(&b), 4);
+    assert_eq!(RInterned::strong_count(&c), 2);
 
     drop(aa);
     drop(bb);
     drop(cb);
 
-    assert_eq!(Interned::strong_count(&a), 3);
-    assert_eq!(Interned::strong_count(&b), 3);
-    assert_eq!(Interned::strong_count(&c), 2);
+    assert_eq!(RInterned::strong_count(&a), 2);
+    assert_eq!(RInterned::strong_count(&b), 2);
+    assert_eq!(RInterned::strong_count(&c), 1);
 
     drop(a);
 
-    assert_eq!(Interned::strong_count(&b), 2);
-    assert_eq!(Interned::strong_count(&c), 2);
+    assert_eq!(RInterned::strong_count(&b), 1);
+    assert_eq!(RInterned::strong_count(&c), 1);
 
     drop(b);
     drop(c);
 
-    assert_eq!(interner.len(), 2);
+    // The interner never held a strong reference, so both values are
+    // reclaimed the moment their last handle drops, with no `gc()` needed.
+    assert_eq!(interner.len(), 0);
 
     interner.gc();
 